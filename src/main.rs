@@ -30,10 +30,75 @@ enum Testament {
     New,
 }
 
+/// BM25 tuning constants used by `Bible::search`. `k1` controls term
+/// frequency saturation, `b` controls how much verse length is normalized
+/// against the average.
+const BM25_K1: f64 = 1.2;
+const BM25_B: f64 = 0.75;
+
+/// An inverted index built once at load time so `Bible::search` can rank
+/// results instead of doing a linear `to_lowercase().contains()` scan on
+/// every keystroke. Verses are assigned a stable global id; `verse_refs` and
+/// `verse_lengths` are parallel vectors indexed by that id.
+#[derive(Debug, Clone, Default)]
+struct SearchIndex {
+    /// Global verse id -> (book, chapter, verse_number).
+    verse_refs: Vec<(String, u32, u32)>,
+    /// Global verse id -> token count, used for BM25 length normalization.
+    verse_lengths: Vec<u32>,
+    /// Lowercased word token -> list of (verse id, term frequency) pairs.
+    /// The list length is the token's document frequency.
+    postings: HashMap<String, Vec<(usize, u32)>>,
+    average_length: f64,
+}
+
+/// Splits text into lowercased word tokens on non-alphanumeric boundaries.
+fn tokenize(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_string())
+        .collect()
+}
+
+/// Walks every verse in `books` and builds the postings lists and length
+/// statistics `Bible::search` needs for BM25 scoring.
+fn build_search_index(books: &[Book]) -> SearchIndex {
+    let mut index = SearchIndex::default();
+
+    for book in books {
+        for chapter in &book.chapters {
+            for verse in &chapter.verses {
+                let verse_id = index.verse_refs.len();
+                index.verse_refs.push((book.name.clone(), chapter.number, verse.verse_number));
+
+                let mut term_counts: HashMap<String, u32> = HashMap::new();
+                for token in tokenize(&verse.text) {
+                    *term_counts.entry(token).or_insert(0) += 1;
+                }
+                index.verse_lengths.push(term_counts.values().sum());
+
+                for (token, count) in term_counts {
+                    index.postings.entry(token).or_default().push((verse_id, count));
+                }
+            }
+        }
+    }
+
+    index.average_length = if index.verse_lengths.is_empty() {
+        0.0
+    } else {
+        index.verse_lengths.iter().sum::<u32>() as f64 / index.verse_lengths.len() as f64
+    };
+
+    index
+}
+
 // Structure to represent the entire Bible
 #[derive(Debug, Clone)]
 struct Bible {
     books: Vec<Book>,
+    search_index: SearchIndex,
 }
 
 impl Bible {
@@ -49,7 +114,7 @@ impl Bible {
         }
         None
     }
-    
+
     // Method to get an entire chapter
     fn get_chapter(&self, book_name: &str, chapter: u32) -> Option<&Chapter> {
         for book in &self.books {
@@ -59,26 +124,155 @@ impl Bible {
         }
         None
     }
-    
-    // Method to search for text in all verses
+
+    /// Searches for `query` using the BM25-ranked inverted index, returning
+    /// results best-match-first. A query wrapped in double quotes instead
+    /// falls back to an exact substring scan, since phrase matching isn't
+    /// something single-token postings can score.
     fn search(&self, query: &str) -> Vec<&Verse> {
-        let query = query.to_lowercase();
+        let trimmed = query.trim();
+        if trimmed.len() >= 2 && trimmed.starts_with('"') && trimmed.ends_with('"') {
+            return self.search_exact_phrase(&trimmed[1..trimmed.len() - 1]);
+        }
+
+        let query_tokens = tokenize(trimmed);
+        if query_tokens.is_empty() {
+            return Vec::new();
+        }
+
+        let total_verses = self.search_index.verse_refs.len() as f64;
+        let average_length = self.search_index.average_length.max(1.0);
+        let mut scores: HashMap<usize, f64> = HashMap::new();
+
+        for token in &query_tokens {
+            let Some(postings) = self.search_index.postings.get(token) else {
+                continue;
+            };
+            let doc_frequency = postings.len() as f64;
+            let idf = ((total_verses - doc_frequency + 0.5) / (doc_frequency + 0.5) + 1.0).ln();
+
+            for &(verse_id, term_frequency) in postings {
+                let tf = term_frequency as f64;
+                let length = self.search_index.verse_lengths[verse_id] as f64;
+                let denom = tf + BM25_K1 * (1.0 - BM25_B + BM25_B * (length / average_length));
+                *scores.entry(verse_id).or_insert(0.0) += idf * (tf * (BM25_K1 + 1.0)) / denom;
+            }
+        }
+
+        let mut ranked: Vec<(usize, f64)> = scores.into_iter().collect();
+        ranked.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+
+        ranked
+            .into_iter()
+            .filter_map(|(verse_id, _)| {
+                let (book, chapter, verse) = &self.search_index.verse_refs[verse_id];
+                self.get_verse(book, *chapter, *verse)
+            })
+            .collect()
+    }
+
+    fn search_exact_phrase(&self, phrase: &str) -> Vec<&Verse> {
+        let phrase = phrase.to_lowercase();
         let mut results = Vec::new();
-        
+
         for book in &self.books {
             for chapter in &book.chapters {
                 for verse in &chapter.verses {
-                    if verse.text.to_lowercase().contains(&query) {
+                    if verse.text.to_lowercase().contains(&phrase) {
                         results.push(verse);
                     }
                 }
             }
         }
-        
+
         results
     }
 }
 
+#[cfg(test)]
+mod search_tests {
+    use super::*;
+
+    /// Builds a `Bible` out of a flat list of `(book, chapter, verse, text)`
+    /// tuples, with its search index built the same way the real
+    /// constructors build theirs, for testing `Bible::search` without
+    /// needing real translation files on disk.
+    fn test_bible(verses: Vec<(&str, u32, u32, &str)>) -> Bible {
+        let mut books: Vec<Book> = Vec::new();
+        for (book_name, chapter_num, verse_num, text) in verses {
+            let book = match books.iter_mut().find(|b| b.name == book_name) {
+                Some(b) => b,
+                None => {
+                    books.push(Book { name: book_name.to_string(), testament: Testament::New, chapters: Vec::new() });
+                    books.last_mut().unwrap()
+                }
+            };
+            while book.chapters.len() < chapter_num as usize {
+                book.chapters.push(Chapter { number: book.chapters.len() as u32 + 1, verses: Vec::new() });
+            }
+            book.chapters[chapter_num as usize - 1].verses.push(Verse {
+                book: book_name.to_string(),
+                chapter: chapter_num,
+                verse_number: verse_num,
+                text: text.to_string(),
+            });
+        }
+        let search_index = build_search_index(&books);
+        Bible { books, search_index }
+    }
+
+    #[test]
+    fn tokenize_splits_on_punctuation_and_lowercases() {
+        assert_eq!(tokenize("Hello, World! It's 2026."), vec!["hello", "world", "it", "s", "2026"]);
+    }
+
+    #[test]
+    fn ranks_the_higher_term_frequency_verse_first() {
+        let bible = test_bible(vec![
+            ("Test", 1, 1, "love love love one another"),
+            ("Test", 1, 2, "love thy neighbor"),
+        ]);
+        let results = bible.search("love");
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].verse_number, 1);
+        assert_eq!(results[1].verse_number, 2);
+    }
+
+    #[test]
+    fn multi_term_query_favors_the_verse_matching_both_terms() {
+        let bible = test_bible(vec![
+            ("Test", 1, 1, "faith and hope and love"),
+            ("Test", 1, 2, "faith alone"),
+            ("Test", 1, 3, "love alone"),
+        ]);
+        let results = bible.search("faith love");
+        assert_eq!(results.first().map(|v| v.verse_number), Some(1));
+    }
+
+    #[test]
+    fn quoted_query_falls_back_to_exact_phrase_match() {
+        let bible = test_bible(vec![
+            ("Test", 1, 1, "love one another as I have loved you"),
+            ("Test", 1, 2, "love thy neighbor as thyself"),
+        ]);
+        let results = bible.search("\"love one another\"");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].verse_number, 1);
+    }
+
+    #[test]
+    fn search_with_no_matching_terms_returns_empty_without_panicking() {
+        let bible = test_bible(vec![("Test", 1, 1, "love one another")]);
+        assert!(bible.search("xyzzy").is_empty());
+    }
+
+    #[test]
+    fn search_with_empty_query_returns_empty() {
+        let bible = test_bible(vec![("Test", 1, 1, "love one another")]);
+        assert!(bible.search("   ").is_empty());
+    }
+}
+
 use std::fs::{self, File};
 use std::io::{self, BufRead};
 use std::path::Path;
@@ -86,24 +280,26 @@ use std::collections::HashMap;
 
 impl Bible {
     fn from_directories(old_testament_path: &Path, new_testament_path: &Path) -> io::Result<Self> {
-        let mut bible = Bible { books: Vec::new() };
-        
+        let mut bible = Bible { books: Vec::new(), search_index: SearchIndex::default() };
+
         // Get the standard book order
         let book_order = get_standard_book_order();
-        
+
         // Read Old Testament books
         Self::read_testament_books(&mut bible, old_testament_path, Testament::Old)?;
-        
+
         // Read New Testament books
         Self::read_testament_books(&mut bible, new_testament_path, Testament::New)?;
-        
+
         // Sort books according to the standard biblical order
         bible.books.sort_by(|a, b| {
             let a_order = book_order.get(&a.name).unwrap_or(&999);
             let b_order = book_order.get(&b.name).unwrap_or(&999);
             a_order.cmp(b_order)
         });
-        
+
+        bible.search_index = build_search_index(&bible.books);
+
         Ok(bible)
     }
     
@@ -193,6 +389,263 @@ impl Bible {
     }
 }
 
+impl Testament {
+    fn to_db_value(&self) -> i64 {
+        match self {
+            Testament::Old => 0,
+            Testament::New => 1,
+        }
+    }
+
+    fn from_db_value(value: i64) -> Self {
+        if value == 1 {
+            Testament::New
+        } else {
+            Testament::Old
+        }
+    }
+}
+
+use rusqlite::{params, Connection, OptionalExtension};
+
+impl Bible {
+    /// Loads the Bible from a SQLite database created by
+    /// `import_directories_to_sqlite`, instead of re-parsing the flat text
+    /// files on every launch. Expects a `verses(book, testament, chapter,
+    /// verse, text)` table, and reads it with a single indexed query rather
+    /// than the line-by-line text parsing `from_directories` does.
+    ///
+    /// This still eagerly loads every verse into the same in-memory
+    /// `Vec<Book>` that `from_directories` builds, so the GUI's translation
+    /// picker, parallel view, and BM25 search index (which all expect to
+    /// walk `self.books`) can use it unchanged. If you just need to answer
+    /// one reference without loading anything into RAM, use
+    /// `sqlite_get_verse`/`sqlite_get_chapter` directly instead - that's
+    /// what `main`'s `--db`/`--lookup` flags do.
+    fn from_sqlite(path: &Path) -> rusqlite::Result<Self> {
+        let conn = Connection::open(path)?;
+        let mut bible = Bible { books: Vec::new(), search_index: SearchIndex::default() };
+        let book_order = get_standard_book_order();
+
+        let mut stmt = conn.prepare(
+            "SELECT book, testament, chapter, verse, text FROM verses \
+             ORDER BY book, chapter, verse",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, i64>(1)?,
+                row.get::<_, u32>(2)?,
+                row.get::<_, u32>(3)?,
+                row.get::<_, String>(4)?,
+            ))
+        })?;
+
+        for row in rows {
+            let (book_name, testament, chapter_num, verse_num, text) = row?;
+
+            // A chapter 0 can't be stored (chapter_num - 1 would underflow),
+            // so skip the row and warn instead of panicking. Reachable with
+            // a hand-edited or otherwise non-conforming `--db` file, not
+            // just ones this binary produced.
+            if chapter_num == 0 {
+                eprintln!("Warning: skipping row with chapter 0 for book {}", book_name);
+                continue;
+            }
+
+            let book = match bible.books.iter_mut().find(|b| b.name == book_name) {
+                Some(book) => book,
+                None => {
+                    bible.books.push(Book {
+                        name: book_name.clone(),
+                        testament: Testament::from_db_value(testament),
+                        chapters: Vec::new(),
+                    });
+                    bible.books.last_mut().unwrap()
+                }
+            };
+
+            while book.chapters.len() < chapter_num as usize {
+                book.chapters.push(Chapter {
+                    number: book.chapters.len() as u32 + 1,
+                    verses: Vec::new(),
+                });
+            }
+            book.chapters[chapter_num as usize - 1].verses.push(Verse {
+                book: book_name,
+                chapter: chapter_num,
+                verse_number: verse_num,
+                text,
+            });
+        }
+
+        bible.books.sort_by(|a, b| {
+            let a_order = book_order.get(&a.name).unwrap_or(&999);
+            let b_order = book_order.get(&b.name).unwrap_or(&999);
+            a_order.cmp(b_order)
+        });
+
+        bible.search_index = build_search_index(&bible.books);
+
+        Ok(bible)
+    }
+
+    /// Parses the existing flat-file directories once and writes every
+    /// verse into a SQLite database at `db_path`, creating the `verses`
+    /// table if it doesn't already exist. This gives a one-time migration
+    /// path from the text format to `from_sqlite`. Reachable from `main` via
+    /// the `--import-db <path>` flag.
+    fn import_directories_to_sqlite(
+        old_testament_path: &Path,
+        new_testament_path: &Path,
+        db_path: &Path,
+    ) -> rusqlite::Result<()> {
+        let bible = Bible::from_directories(old_testament_path, new_testament_path)
+            .map_err(|e| rusqlite::Error::InvalidPath(std::path::PathBuf::from(e.to_string())))?;
+
+        let conn = Connection::open(db_path)?;
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS verses (
+                book TEXT NOT NULL,
+                testament INTEGER NOT NULL,
+                chapter INTEGER NOT NULL,
+                verse INTEGER NOT NULL,
+                text TEXT NOT NULL
+            )",
+            [],
+        )?;
+        // Unique so re-running this against the same db_path replaces each
+        // verse's row instead of silently duplicating it - without this,
+        // a second import would double every token's BM25 document
+        // frequency and duplicate every verse `from_sqlite` loads.
+        conn.execute(
+            "CREATE UNIQUE INDEX IF NOT EXISTS idx_verses_lookup ON verses(book, chapter, verse)",
+            [],
+        )?;
+
+        for book in &bible.books {
+            for chapter in &book.chapters {
+                for verse in &chapter.verses {
+                    conn.execute(
+                        "INSERT OR REPLACE INTO verses (book, testament, chapter, verse, text) VALUES (?1, ?2, ?3, ?4, ?5)",
+                        params![
+                            book.name,
+                            book.testament.to_db_value(),
+                            chapter.number,
+                            verse.verse_number,
+                            verse.text
+                        ],
+                    )?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod sqlite_round_trip_tests {
+    use super::*;
+
+    fn write_book(dir: &Path, name: &str, contents: &str) {
+        fs::write(dir.join(format!("{}.txt", name)), contents).unwrap();
+    }
+
+    /// Sets up a scratch `old_testament`/`new_testament` directory pair
+    /// under the system temp dir, unique per test (by label) and per test
+    /// process (by process id) so parallel `cargo test` runs don't collide.
+    fn scratch_dirs(label: &str) -> (std::path::PathBuf, std::path::PathBuf, std::path::PathBuf) {
+        let root = std::env::temp_dir().join(format!("bible_app_test_{}_{}", label, std::process::id()));
+        let old_testament = root.join("old_testament");
+        let new_testament = root.join("new_testament");
+        fs::create_dir_all(&old_testament).unwrap();
+        fs::create_dir_all(&new_testament).unwrap();
+        (root, old_testament, new_testament)
+    }
+
+    #[test]
+    fn import_then_load_round_trips_every_verse() {
+        let (root, old_testament, new_testament) = scratch_dirs("roundtrip");
+        write_book(&old_testament, "Genesis", "1:1 In the beginning God created the heavens and the earth.\n");
+        write_book(&new_testament, "John", "3:16 For God so loved the world.\n");
+
+        let db_path = root.join("bible.db");
+        Bible::import_directories_to_sqlite(&old_testament, &new_testament, &db_path).unwrap();
+        let loaded = Bible::from_sqlite(&db_path).unwrap();
+
+        assert_eq!(
+            loaded.get_verse("Genesis", 1, 1).map(|v| v.text.as_str()),
+            Some("In the beginning God created the heavens and the earth.")
+        );
+        assert_eq!(
+            loaded.get_verse("John", 3, 16).map(|v| v.text.as_str()),
+            Some("For God so loved the world.")
+        );
+        // Genesis comes before John in the standard biblical order that
+        // `from_sqlite` sorts its books into (and also alphabetically, the
+        // order its query reads the rows in).
+        assert_eq!(loaded.books.first().map(|b| b.name.as_str()), Some("Genesis"));
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn reimporting_the_same_path_does_not_duplicate_rows() {
+        let (root, old_testament, new_testament) = scratch_dirs("dedup");
+        write_book(&old_testament, "Genesis", "1:1 In the beginning.\n");
+        write_book(&new_testament, "John", "3:16 For God so loved the world.\n");
+
+        let db_path = root.join("bible.db");
+        Bible::import_directories_to_sqlite(&old_testament, &new_testament, &db_path).unwrap();
+        Bible::import_directories_to_sqlite(&old_testament, &new_testament, &db_path).unwrap();
+
+        let loaded = Bible::from_sqlite(&db_path).unwrap();
+        let genesis = loaded.books.iter().find(|b| b.name == "Genesis").unwrap();
+        assert_eq!(genesis.chapters[0].verses.len(), 1);
+
+        let _ = fs::remove_dir_all(&root);
+    }
+}
+
+/// Looks up a single verse directly against a SQLite database built by
+/// `import_directories_to_sqlite`: one indexed query, no `Vec<Book>` ever
+/// held in memory. This is the actually-lazy lookup path the flat-file and
+/// `from_sqlite` constructors can't offer, reachable via `main`'s
+/// `--db`/`--lookup` flags.
+fn sqlite_get_verse(conn: &Connection, book: &str, chapter: u32, verse: u32) -> rusqlite::Result<Option<Verse>> {
+    conn.query_row(
+        "SELECT book, chapter, verse, text FROM verses WHERE book = ?1 AND chapter = ?2 AND verse = ?3",
+        params![book, chapter, verse],
+        |row| {
+            Ok(Verse {
+                book: row.get(0)?,
+                chapter: row.get(1)?,
+                verse_number: row.get(2)?,
+                text: row.get(3)?,
+            })
+        },
+    )
+    .optional()
+}
+
+/// Looks up every verse of one chapter the same way `sqlite_get_verse`
+/// looks up one verse: a single indexed query, no eager `Vec<Book>` load.
+fn sqlite_get_chapter(conn: &Connection, book: &str, chapter: u32) -> rusqlite::Result<Vec<Verse>> {
+    let mut stmt = conn.prepare(
+        "SELECT book, chapter, verse, text FROM verses WHERE book = ?1 AND chapter = ?2 ORDER BY verse",
+    )?;
+    let rows = stmt.query_map(params![book, chapter], |row| {
+        Ok(Verse {
+            book: row.get(0)?,
+            chapter: row.get(1)?,
+            verse_number: row.get(2)?,
+            text: row.get(3)?,
+        })
+    })?;
+    rows.collect()
+}
+
 // Function that provides the standard biblical order
 fn get_standard_book_order() -> HashMap<String, usize> {
     let books = vec![
@@ -223,87 +676,668 @@ fn get_standard_book_order() -> HashMap<String, usize> {
     order_map
 }
 
+/// Encodes a `(book, chapter, verse)` passage into a single stable integer,
+/// using `get_standard_book_order`'s index for the book component. Used to
+/// keep bookmarks and navigation history compact and stable even if the
+/// on-disk file format changes later.
+fn passage_to_int(book: &str, chapter: u32, verse: u32) -> Option<i64> {
+    let book_index = *get_standard_book_order().get(book)? as i64;
+    Some(book_index * 1_000_000 + chapter as i64 * 1_000 + verse as i64)
+}
+
+/// Inverse of `passage_to_int`.
+fn passage_from_int(id: i64) -> Option<(String, u32, u32)> {
+    let book_index = id / 1_000_000;
+    let chapter = (id / 1_000) % 1_000;
+    let verse = id % 1_000;
+
+    let book_name = get_standard_book_order()
+        .into_iter()
+        .find(|(_, index)| *index as i64 == book_index)
+        .map(|(name, _)| name)?;
+
+    Some((book_name, chapter as u32, verse as u32))
+}
+
+#[cfg(test)]
+mod passage_id_tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_encode_and_decode() {
+        let id = passage_to_int("John", 3, 16).unwrap();
+        assert_eq!(passage_from_int(id), Some(("John".to_string(), 3, 16)));
+    }
+
+    #[test]
+    fn round_trips_the_first_and_last_books() {
+        let genesis = passage_to_int("Genesis", 1, 1).unwrap();
+        assert_eq!(passage_from_int(genesis), Some(("Genesis".to_string(), 1, 1)));
+
+        let revelation = passage_to_int("Revelation", 22, 21).unwrap();
+        assert_eq!(passage_from_int(revelation), Some(("Revelation".to_string(), 22, 21)));
+    }
+
+    #[test]
+    fn rejects_an_unknown_book() {
+        assert_eq!(passage_to_int("Nonexistent", 1, 1), None);
+    }
+}
+
+/// Where bookmark and last-read state is persisted between runs. Plain
+/// `key=value` lines, matching the rest of the crate's hand-rolled text
+/// parsing rather than pulling in a JSON dependency for a handful of ints.
+const BOOKMARKS_FILE: &str = "bookmarks.txt";
+
+#[derive(Debug, Clone, Default)]
+struct BookmarkStore {
+    last_read: Option<i64>,
+    bookmarks: HashMap<char, i64>,
+}
+
+impl BookmarkStore {
+    fn load() -> Self {
+        let Ok(contents) = fs::read_to_string(BOOKMARKS_FILE) else {
+            return Self::default();
+        };
+
+        let mut store = Self::default();
+        for line in contents.lines() {
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            let Ok(id) = value.trim().parse::<i64>() else {
+                continue;
+            };
+
+            if key == "last_read" {
+                store.last_read = Some(id);
+            } else if let Some(bookmark_char) = key.strip_prefix("bookmark:").and_then(|c| c.chars().next()) {
+                store.bookmarks.insert(bookmark_char, id);
+            }
+        }
+        store
+    }
+
+    fn save(&self) {
+        let mut contents = String::new();
+        if let Some(id) = self.last_read {
+            contents.push_str(&format!("last_read={}\n", id));
+        }
+        for (bookmark_char, id) in &self.bookmarks {
+            contents.push_str(&format!("bookmark:{}={}\n", bookmark_char, id));
+        }
+
+        if let Err(e) = fs::write(BOOKMARKS_FILE, contents) {
+            eprintln!("Error saving bookmarks: {}", e);
+        }
+    }
+}
+
+/// Sentinel verse number meaning "the last verse in the chapter". Used by
+/// `parse_reference` when a range ends in the literal `end` keyword, or when
+/// a cross-chapter range includes a whole chapter whose length isn't known
+/// until `Bible::resolve` looks it up. It always appears immediately after
+/// another tuple for the same book and chapter, and the pair together means
+/// "from that tuple's verse through the last verse of the chapter".
+const LAST_VERSE_IN_CHAPTER: u32 = u32::MAX;
+
+/// Parses a textual Bible reference such as "John 3:16", "1 Samuel 2:1-10",
+/// or "Genesis 1:1-2:3" into a flat list of `(book, chapter, verse)`
+/// pointers. Book names are normalized to the spaceless form used by
+/// `get_standard_book_order` (e.g. "Song of Solomon" -> "SongofSolomon").
+///
+/// Verse ranges with a known numeric end are expanded immediately since no
+/// Bible data is required. A range that ends in `end`/`END`, or a
+/// cross-chapter range's whole chapters in between, are represented with
+/// `LAST_VERSE_IN_CHAPTER` (see its doc comment); `Bible::resolve` expands
+/// those once it can look up the chapter's actual verse count.
+fn parse_reference(input: &str) -> Option<Vec<(String, u32, u32)>> {
+    let input = input.trim();
+    if input.is_empty() {
+        return None;
+    }
+
+    // Split off the book name at the *last* whitespace first, so the
+    // separator search below only looks inside the trailing
+    // "<chapter><sep><verses>" token and can't match a 'v' that's part of
+    // the book name itself (e.g. "Revelation", "Leviticus").
+    let (book_part, chapter_and_verse) = input.rsplit_once(char::is_whitespace)?;
+    let sep_pos = chapter_and_verse.find([':', 'v', 'V'])?;
+    let (chapter_str, verse_part) = chapter_and_verse.split_at(sep_pos);
+    let verse_part = &verse_part[1..];
+
+    let start_chapter: u32 = chapter_str.trim().parse().ok()?;
+    let book = normalize_book_name(book_part.trim())?;
+
+    let mut refs = Vec::new();
+    for (i, segment) in verse_part.split(',').enumerate() {
+        // Only the first comma-separated segment may specify a cross-chapter
+        // range (e.g. "1:1-2:3"); later segments are additional ranges
+        // within the starting chapter (e.g. "3:16,18-20").
+        let segment_refs = parse_verse_segment(&book, start_chapter, segment.trim(), i == 0)?;
+        refs.extend(segment_refs);
+    }
+
+    Some(refs)
+}
+
+/// Parses a single comma-separated piece of a reference's verse part, e.g.
+/// "16", "1-10", or "1-2:3". See `parse_reference` for the overall grammar.
+fn parse_verse_segment(
+    book: &str,
+    chapter: u32,
+    segment: &str,
+    allow_cross_chapter: bool,
+) -> Option<Vec<(String, u32, u32)>> {
+    let (start_str, end_str) = match segment.split_once('-') {
+        Some((s, e)) => (s, Some(e.trim())),
+        None => (segment, None),
+    };
+    let start_verse: u32 = start_str.trim().parse().ok()?;
+
+    let Some(end_str) = end_str else {
+        return Some(vec![(book.to_string(), chapter, start_verse)]);
+    };
+
+    if end_str.eq_ignore_ascii_case("end") {
+        return Some(vec![
+            (book.to_string(), chapter, start_verse),
+            (book.to_string(), chapter, LAST_VERSE_IN_CHAPTER),
+        ]);
+    }
+
+    if let Some(end_sep_pos) = end_str.find([':', 'v', 'V']) {
+        if !allow_cross_chapter {
+            return None;
+        }
+        let end_chapter: u32 = end_str[..end_sep_pos].trim().parse().ok()?;
+        let end_verse: u32 = end_str[end_sep_pos + 1..].trim().parse().ok()?;
+
+        let mut refs = vec![
+            (book.to_string(), chapter, start_verse),
+            (book.to_string(), chapter, LAST_VERSE_IN_CHAPTER),
+        ];
+        for mid_chapter in (chapter + 1)..end_chapter {
+            refs.push((book.to_string(), mid_chapter, 1));
+            refs.push((book.to_string(), mid_chapter, LAST_VERSE_IN_CHAPTER));
+        }
+        refs.extend((1..=end_verse).map(|v| (book.to_string(), end_chapter, v)));
+        return Some(refs);
+    }
+
+    let end_verse: u32 = end_str.parse().ok()?;
+    Some((start_verse..=end_verse).map(|v| (book.to_string(), chapter, v)).collect())
+}
+
+/// Normalizes a book name as typed by a user (e.g. "song of solomon") to the
+/// spaceless form used throughout the crate (e.g. "SongofSolomon"), matching
+/// case-insensitively against `get_standard_book_order`.
+fn normalize_book_name(raw: &str) -> Option<String> {
+    let collapsed: String = raw.split_whitespace().collect();
+    get_standard_book_order()
+        .keys()
+        .find(|name| name.eq_ignore_ascii_case(&collapsed))
+        .cloned()
+}
+
+#[cfg(test)]
+mod reference_parser_tests {
+    use super::*;
+
+    #[test]
+    fn parses_simple_reference() {
+        assert_eq!(parse_reference("John 3:16"), Some(vec![("John".to_string(), 3, 16)]));
+    }
+
+    #[test]
+    fn book_name_containing_v_is_not_mistaken_for_the_separator() {
+        // Regression test: "Revelation"/"Leviticus" both contain a lowercase
+        // 'v', which used to be matched as the chapter:verse separator.
+        assert_eq!(parse_reference("Revelation 3:16"), Some(vec![("Revelation".to_string(), 3, 16)]));
+        assert_eq!(parse_reference("Leviticus 1:1"), Some(vec![("Leviticus".to_string(), 1, 1)]));
+    }
+
+    #[test]
+    fn parses_multi_word_book_name() {
+        assert_eq!(parse_reference("1 Samuel 2:1"), Some(vec![("1Samuel".to_string(), 2, 1)]));
+        assert_eq!(parse_reference("Song of Solomon 1:1"), Some(vec![("SongofSolomon".to_string(), 1, 1)]));
+    }
+
+    #[test]
+    fn parses_verse_range() {
+        assert_eq!(
+            parse_reference("Psalms 23:1-3"),
+            Some(vec![
+                ("Psalms".to_string(), 23, 1),
+                ("Psalms".to_string(), 23, 2),
+                ("Psalms".to_string(), 23, 3),
+            ])
+        );
+    }
+
+    #[test]
+    fn parses_range_to_end_of_chapter() {
+        assert_eq!(
+            parse_reference("John 3:16-end"),
+            Some(vec![("John".to_string(), 3, 16), ("John".to_string(), 3, LAST_VERSE_IN_CHAPTER)])
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_book() {
+        assert_eq!(parse_reference("Nonexistent 1:1"), None);
+    }
+
+    #[test]
+    fn rejects_input_without_a_separator() {
+        assert_eq!(parse_reference("John 3"), None);
+    }
+}
+
+impl Bible {
+    /// Expands parsed reference tuples (see `parse_reference`) into the
+    /// actual verses they point to, resolving any `LAST_VERSE_IN_CHAPTER`
+    /// sentinels against this Bible's chapter data.
+    fn resolve(&self, refs: &[(String, u32, u32)]) -> Vec<&Verse> {
+        let mut verses = Vec::new();
+        let mut i = 0;
+        while i < refs.len() {
+            let (book, chapter, verse) = &refs[i];
+
+            if let Some((next_book, next_chapter, next_verse)) = refs.get(i + 1) {
+                if *next_verse == LAST_VERSE_IN_CHAPTER && next_book == book && next_chapter == chapter {
+                    if let Some(chapter_data) = self.get_chapter(book, *chapter) {
+                        verses.extend(chapter_data.verses.iter().filter(|v| v.verse_number >= *verse));
+                    }
+                    i += 2;
+                    continue;
+                }
+            }
+
+            if let Some(v) = self.get_verse(book, *chapter, *verse) {
+                verses.push(v);
+            }
+            i += 1;
+        }
+        verses
+    }
+
+    /// Returns the chapter after `(book, chapter)`, rolling over to chapter 1
+    /// of the next book once `book`'s chapters are exhausted. Relies on
+    /// `self.books` already being sorted into the standard biblical order by
+    /// the constructors, so "next book" is simply the next entry in the vec.
+    fn next_chapter(&self, book: &str, chapter: u32) -> Option<(String, u32)> {
+        let index = self.books.iter().position(|b| b.name == book)?;
+        if (chapter as usize) < self.books[index].chapters.len() {
+            return Some((book.to_string(), chapter + 1));
+        }
+        self.books.get(index + 1).map(|b| (b.name.clone(), 1))
+    }
+
+    /// Returns the chapter before `(book, chapter)`, rolling over to the last
+    /// chapter of the previous book at the start of a book. See
+    /// `next_chapter` for the ordering assumption.
+    fn prev_chapter(&self, book: &str, chapter: u32) -> Option<(String, u32)> {
+        if chapter > 1 {
+            return Some((book.to_string(), chapter - 1));
+        }
+        let index = self.books.iter().position(|b| b.name == book)?;
+        let previous = index.checked_sub(1).and_then(|i| self.books.get(i))?;
+        Some((previous.name.clone(), previous.chapters.len() as u32))
+    }
+}
+
+#[cfg(test)]
+mod chapter_rollover_tests {
+    use super::*;
+
+    /// A two-book Bible: "First" has 2 chapters, "Second" has 1, enough to
+    /// exercise a mid-book step, a book-boundary rollover in both
+    /// directions, and the start/end-of-Bible `None` cases.
+    fn test_bible() -> Bible {
+        let books = vec![
+            Book {
+                name: "First".to_string(),
+                testament: Testament::Old,
+                chapters: vec![
+                    Chapter { number: 1, verses: Vec::new() },
+                    Chapter { number: 2, verses: Vec::new() },
+                ],
+            },
+            Book {
+                name: "Second".to_string(),
+                testament: Testament::Old,
+                chapters: vec![Chapter { number: 1, verses: Vec::new() }],
+            },
+        ];
+        Bible { search_index: build_search_index(&books), books }
+    }
+
+    #[test]
+    fn next_chapter_steps_within_a_book() {
+        assert_eq!(test_bible().next_chapter("First", 1), Some(("First".to_string(), 2)));
+    }
+
+    #[test]
+    fn next_chapter_rolls_over_to_the_next_book() {
+        assert_eq!(test_bible().next_chapter("First", 2), Some(("Second".to_string(), 1)));
+    }
+
+    #[test]
+    fn next_chapter_is_none_after_the_last_chapter_of_the_last_book() {
+        assert_eq!(test_bible().next_chapter("Second", 1), None);
+    }
+
+    #[test]
+    fn prev_chapter_steps_within_a_book() {
+        assert_eq!(test_bible().prev_chapter("First", 2), Some(("First".to_string(), 1)));
+    }
+
+    #[test]
+    fn prev_chapter_rolls_back_to_the_previous_book() {
+        assert_eq!(test_bible().prev_chapter("Second", 1), Some(("First".to_string(), 2)));
+    }
+
+    #[test]
+    fn prev_chapter_is_none_before_the_first_chapter_of_the_first_book() {
+        assert_eq!(test_bible().prev_chapter("First", 1), None);
+    }
+
+    #[test]
+    fn unknown_book_returns_none() {
+        assert_eq!(test_bible().next_chapter("Nonexistent", 1), None);
+        assert_eq!(test_bible().prev_chapter("Nonexistent", 1), None);
+    }
+}
+
 use egui::{ComboBox, ScrollArea, TextEdit};
 
-struct BibleApp {
+/// One loaded Bible translation, keyed by a short id (e.g. "KJV") shown in
+/// the translation picker and used to key `BibleApp::chapter_by_translation`.
+struct Translation {
+    id: String,
     bible: Bible,
+}
+
+/// How many verses make up one page of the paginated reading mode.
+const VERSES_PER_PAGE: usize = 10;
+
+struct BibleApp {
+    translations: Vec<Translation>,
+    active_translation: String,
+    parallel_mode: bool,
     selected_book: String,
     selected_chapter: u32,
-    chapter_text: String,
-    current_chapter_verses: Vec<Verse>,
+    chapter_by_translation: HashMap<String, Vec<Verse>>,
+    reading_page: usize,
     search_query: String,
     search_results: Vec<Verse>,
     navigate_to: Option<(String, u32)>,
+    bookmarks: BookmarkStore,
+    /// Stack of positions navigated away from, for "jump to previous".
+    navigation_history: Vec<(String, u32)>,
+    /// Single-character key typed into the "mark" box in the bookmarks panel.
+    bookmark_input: String,
 }
 
 impl BibleApp {
-    fn new(bible: Bible) -> Self {
-        let default_book = bible.books.first().map_or("Genesis".to_string(), |b| b.name.clone());
-        let default_chapter = 1;
-        
-        // Get all verses for the default chapter
-        let mut current_chapter_verses = Vec::new();
-        let mut chapter_text = String::new();
-        
-        if let Some(chapter) = bible.get_chapter(&default_book, default_chapter) {
-            current_chapter_verses = chapter.verses.clone();
-            
-            // Format the chapter text
-            for verse in &chapter.verses {
-                chapter_text.push_str(&format!("{} {}\n\n", verse.verse_number, verse.text));
-            }
-        }
-        
-        Self {
-            bible,
+    fn new(translations: Vec<Translation>) -> Self {
+        let bookmarks = BookmarkStore::load();
+
+        let active_translation = translations.first().map_or_else(String::new, |t| t.id.clone());
+        let fallback_book = translations
+            .first()
+            .and_then(|t| t.bible.books.first())
+            .map_or("Genesis".to_string(), |b| b.name.clone());
+
+        // Restore the last-read position if one was saved, otherwise fall
+        // back to the first book of the first translation.
+        let (default_book, default_chapter) = bookmarks
+            .last_read
+            .and_then(passage_from_int)
+            .map(|(book, chapter, _)| (book, chapter))
+            .unwrap_or((fallback_book, 1));
+
+        let mut app = Self {
+            translations,
+            active_translation,
+            parallel_mode: false,
             selected_book: default_book,
             selected_chapter: default_chapter,
-            chapter_text,
-            current_chapter_verses,
+            chapter_by_translation: HashMap::new(),
+            reading_page: 0,
             search_query: "".to_string(),
             search_results: Vec::new(),
             navigate_to: None,
-        }
+            bookmarks,
+            navigation_history: Vec::new(),
+            bookmark_input: String::new(),
+        };
+        app.update_chapter_display();
+        app
     }
-    
+
+    fn active_bible(&self) -> Option<&Bible> {
+        self.translations.iter().find(|t| t.id == self.active_translation).map(|t| &t.bible)
+    }
+
+    /// Rebuilds `chapter_by_translation` for `selected_book`/`selected_chapter`
+    /// across every loaded translation, not just the active one, so the
+    /// parallel view can render them all side by side without re-querying.
+    /// Also resets the reading page and persists the new last-read position.
     fn update_chapter_display(&mut self) {
-        // Clear previous content
-        self.chapter_text.clear();
-        self.current_chapter_verses.clear();
-        
-        // Get the selected chapter
-        if let Some(chapter) = self.bible.get_chapter(&self.selected_book, self.selected_chapter) {
-            self.current_chapter_verses = chapter.verses.clone();
-            
-            // Format the chapter text
-            for verse in &chapter.verses {
-                self.chapter_text.push_str(&format!("{} {}\n\n", verse.verse_number, verse.text));
+        self.chapter_by_translation.clear();
+        self.reading_page = 0;
+
+        for translation in &self.translations {
+            if let Some(chapter) = translation.bible.get_chapter(&self.selected_book, self.selected_chapter) {
+                self.chapter_by_translation.insert(translation.id.clone(), chapter.verses.clone());
             }
-        } else {
-            self.chapter_text = "Chapter not found".to_string();
         }
+
+        if let Some(id) = passage_to_int(&self.selected_book, self.selected_chapter, 1) {
+            self.bookmarks.last_read = Some(id);
+            self.bookmarks.save();
+        }
+    }
+
+    /// Navigates to `(book, chapter)` the same way picking it from the
+    /// dropdowns and pressing "Go" would, remembering where we came from so
+    /// `jump_back` can return to it.
+    fn navigate(&mut self, book: String, chapter: u32) {
+        self.navigation_history.push((self.selected_book.clone(), self.selected_chapter));
+        self.selected_book = book;
+        self.selected_chapter = chapter;
+        self.update_chapter_display();
+    }
+
+    /// Pops the most recent entry off the navigation history and jumps back
+    /// to it, without pushing the current position in turn.
+    fn jump_back(&mut self) {
+        if let Some((book, chapter)) = self.navigation_history.pop() {
+            self.selected_book = book;
+            self.selected_chapter = chapter;
+            self.update_chapter_display();
+        }
+    }
+
+    /// Moves to `(book, chapter)` without touching `navigation_history`, for
+    /// the `]`/`[` single-chapter-step keys - each step isn't worth a "Back"
+    /// entry of its own, only the position before a deliberate jump is.
+    fn step_chapter(&mut self, book: String, chapter: u32) {
+        self.selected_book = book;
+        self.selected_chapter = chapter;
+        self.update_chapter_display();
+    }
+
+    /// Saves the current position as a bookmark under `key`.
+    fn set_bookmark(&mut self, key: char) {
+        if let Some(id) = passage_to_int(&self.selected_book, self.selected_chapter, 1) {
+            self.bookmarks.bookmarks.insert(key, id);
+            self.bookmarks.save();
+        }
+    }
+
+    /// The highest valid `reading_page` for the active translation's current
+    /// chapter, given `VERSES_PER_PAGE`.
+    fn last_reading_page(&self) -> usize {
+        let verse_count = self
+            .chapter_by_translation
+            .get(&self.active_translation)
+            .map_or(0, |verses| verses.len());
+        verse_count.saturating_sub(1) / VERSES_PER_PAGE
+    }
+
+    /// Vim-style keyboard navigation: `]`/`[` step chapters (rolling over at
+    /// book boundaries), `n`/`p`/PageDown/PageUp page through a long chapter,
+    /// and `g`/`G` jump to the first/last page. Disabled while a widget like
+    /// the search box has focus, so typing doesn't also trigger navigation.
+    fn handle_keyboard_navigation(&mut self, ctx: &egui::Context) {
+        if ctx.memory(|m| m.focused().is_some()) {
+            return;
+        }
+
+        let next_chapter = ctx.input(|i| i.key_pressed(egui::Key::CloseBracket));
+        let prev_chapter = ctx.input(|i| i.key_pressed(egui::Key::OpenBracket));
+        let next_page = ctx.input(|i| i.key_pressed(egui::Key::N) || i.key_pressed(egui::Key::PageDown));
+        let prev_page = ctx.input(|i| i.key_pressed(egui::Key::P) || i.key_pressed(egui::Key::PageUp));
+        let jump_start = ctx.input(|i| i.key_pressed(egui::Key::G) && !i.modifiers.shift);
+        let jump_end = ctx.input(|i| i.key_pressed(egui::Key::G) && i.modifiers.shift);
+
+        if next_chapter {
+            let target = self.active_bible().and_then(|b| b.next_chapter(&self.selected_book, self.selected_chapter));
+            if let Some((book, chapter)) = target {
+                self.step_chapter(book, chapter);
+            }
+        } else if prev_chapter {
+            let target = self.active_bible().and_then(|b| b.prev_chapter(&self.selected_book, self.selected_chapter));
+            if let Some((book, chapter)) = target {
+                self.step_chapter(book, chapter);
+            }
+        } else if next_page {
+            self.reading_page = (self.reading_page + 1).min(self.last_reading_page());
+        } else if prev_page {
+            self.reading_page = self.reading_page.saturating_sub(1);
+        } else if jump_start {
+            self.reading_page = 0;
+        } else if jump_end {
+            self.reading_page = self.last_reading_page();
+        }
+    }
+}
+
+#[cfg(test)]
+mod translation_loading_tests {
+    use super::*;
+
+    /// Book names deliberately absent from `get_standard_book_order()`, so
+    /// `passage_to_int` returns `None` and `update_chapter_display` doesn't
+    /// write `bookmarks.txt` as a side effect of these tests.
+    fn translation(id: &str, text: &str) -> Translation {
+        let books = vec![Book {
+            name: "Test".to_string(),
+            testament: Testament::New,
+            chapters: vec![Chapter {
+                number: 1,
+                verses: vec![Verse { book: "Test".to_string(), chapter: 1, verse_number: 1, text: text.to_string() }],
+            }],
+        }];
+        Translation { id: id.to_string(), bible: Bible { search_index: build_search_index(&books), books } }
+    }
+
+    fn app_with_translations() -> BibleApp {
+        BibleApp::new(vec![translation("KJV", "In the beginning."), translation("ASV", "In the beginning, ASV.")])
+    }
+
+    #[test]
+    fn active_bible_returns_the_bible_matching_active_translation() {
+        let app = app_with_translations();
+        assert_eq!(app.active_translation, "KJV");
+        assert_eq!(app.active_bible().unwrap().get_verse("Test", 1, 1).unwrap().text, "In the beginning.");
+    }
+
+    #[test]
+    fn active_bible_is_none_for_an_unknown_translation_id() {
+        let mut app = app_with_translations();
+        app.active_translation = "NIV".to_string();
+        assert!(app.active_bible().is_none());
+    }
+
+    #[test]
+    fn update_chapter_display_populates_every_loaded_translation() {
+        let app = app_with_translations();
+        assert_eq!(app.chapter_by_translation.len(), 2);
+        assert_eq!(app.chapter_by_translation["KJV"][0].text, "In the beginning.");
+        assert_eq!(app.chapter_by_translation["ASV"][0].text, "In the beginning, ASV.");
+    }
+
+    #[test]
+    fn update_chapter_display_drops_translations_missing_the_selected_chapter() {
+        let mut app = app_with_translations();
+        app.selected_chapter = 2;
+        app.update_chapter_display();
+        assert!(app.chapter_by_translation.is_empty());
     }
 }
 
+/// Returns the slice of `verses` making up `page` (0-indexed), per
+/// `VERSES_PER_PAGE`.
+fn paginate(verses: &[Verse], page: usize) -> &[Verse] {
+    let start = (page * VERSES_PER_PAGE).min(verses.len());
+    let end = (start + VERSES_PER_PAGE).min(verses.len());
+    &verses[start..end]
+}
+
 impl eframe::App for BibleApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        self.handle_keyboard_navigation(ctx);
+
         egui::CentralPanel::default().show(ctx, |ui| {
             ui.heading("Bible Reader");
             
             ui.horizontal(|ui| {
-                // Book selection dropdown
+                // Captured before the Book/Chapter dropdowns below can mutate
+                // them, so "Go" can push the position being left onto
+                // navigation_history instead of the one it's headed to.
+                let previous_book = self.selected_book.clone();
+                let previous_chapter = self.selected_chapter;
+
+                // Translation picker - switching translations re-queries the
+                // current book/chapter from the newly active translation.
+                let previous_translation = self.active_translation.clone();
+                ComboBox::from_label("Translation")
+                    .selected_text(&self.active_translation)
+                    .show_ui(ui, |ui| {
+                        for translation in &self.translations {
+                            ui.selectable_value(&mut self.active_translation, translation.id.clone(), &translation.id);
+                        }
+                    });
+                if self.active_translation != previous_translation {
+                    self.update_chapter_display();
+                }
+
+                ui.checkbox(&mut self.parallel_mode, "Parallel");
+
+                // Book selection dropdown. Collect the names into an owned
+                // Vec first so the immutable borrow of `self` from
+                // `active_bible()` ends before `selectable_value` needs to
+                // borrow `self.selected_book` mutably.
+                let book_names: Vec<String> = self
+                    .active_bible()
+                    .map_or(Vec::new(), |bible| bible.books.iter().map(|b| b.name.clone()).collect());
                 ComboBox::from_label("Book")
                     .selected_text(&self.selected_book)
                     .show_ui(ui, |ui| {
-                        for book in &self.bible.books {
-                            ui.selectable_value(&mut self.selected_book, book.name.clone(), &book.name);
+                        for name in &book_names {
+                            ui.selectable_value(&mut self.selected_book, name.clone(), name);
                         }
                     });
-                
+
                 // Find the selected book to get chapter count
-                if let Some(book) = self.bible.books.iter().find(|b| b.name == self.selected_book) {
+                if let Some(book) = self.active_bible().and_then(|b| b.books.iter().find(|b| b.name == self.selected_book)) {
                     let chapter_count = book.chapters.len() as u32;
-                    
+
                     // Chapter selection dropdown
                     ComboBox::from_label("Chapter")
                         .selected_text(self.selected_chapter.to_string())
@@ -313,30 +1347,71 @@ impl eframe::App for BibleApp {
                             }
                         });
                 }
-                
-                // Update chapter text when selection changes
+
+                // Update chapter text when selection changes. Pushes the
+                // pre-click position onto navigation_history, like
+                // `navigate()` does for search/bookmark jumps, so "« Back"
+                // also covers the Book/Chapter dropdowns - the path most
+                // users actually browse with. Can't just call `navigate()`
+                // here since the dropdowns above already mutated
+                // `selected_book`/`selected_chapter` in place.
                 if ui.button("Go").clicked() {
+                    self.navigation_history.push((previous_book.clone(), previous_chapter));
                     self.update_chapter_display();
                 }
             });
-            
+
             // Display the selected chapter
             ui.add_space(10.0);
             ui.heading(format!("{} Chapter {}", self.selected_book, self.selected_chapter));
+            ui.label(format!("Page {} of {}", self.reading_page + 1, self.last_reading_page() + 1));
             ui.separator();
-            
-            // Use ScrollArea for displaying the chapter text
-            ScrollArea::vertical()
-                .max_height(300.0)
-                .id_salt("chapter_scroll")
-                .show(ui, |ui| {
-                    ui.add(TextEdit::multiline(&mut self.chapter_text)
-                         .desired_width(f32::INFINITY)
-                         .desired_rows(10)
-                         .interactive(false)
-                         .margin(egui::vec2(8.0, 8.0)));
+
+            if self.parallel_mode {
+                // Render every loaded translation in its own column, each
+                // listing its verses in verse_number order. The columns
+                // scroll independently; rows are not matched up across
+                // columns by verse_number.
+                ui.horizontal(|ui| {
+                    for translation in &self.translations {
+                        ui.vertical(|ui| {
+                            ui.label(&translation.id);
+                            ScrollArea::vertical()
+                                .max_height(300.0)
+                                .id_salt(format!("chapter_scroll_{}", translation.id))
+                                .show(ui, |ui| {
+                                    match self.chapter_by_translation.get(&translation.id) {
+                                        Some(verses) => {
+                                            for verse in paginate(verses, self.reading_page) {
+                                                ui.label(format!("{} {}", verse.verse_number, verse.text));
+                                            }
+                                        }
+                                        None => {
+                                            ui.label("Chapter not found");
+                                        }
+                                    }
+                                });
+                        });
+                    }
                 });
-                
+            } else {
+                ScrollArea::vertical()
+                    .max_height(300.0)
+                    .id_salt("chapter_scroll")
+                    .show(ui, |ui| {
+                        match self.chapter_by_translation.get(&self.active_translation) {
+                            Some(verses) => {
+                                for verse in paginate(verses, self.reading_page) {
+                                    ui.label(format!("{} {}", verse.verse_number, verse.text));
+                                }
+                            }
+                            None => {
+                                ui.label("Chapter not found");
+                            }
+                        }
+                    });
+            }
+
             ui.separator();
             
             // Search functionality
@@ -351,9 +1426,28 @@ impl eframe::App for BibleApp {
                 ui.add(text_edit);
                 
                 if ui.button("Search").clicked() {
-                    // Perform search and convert results
-                    let results = self.bible.search(&self.search_query);
-                    self.search_results = results.iter().map(|v| (*v).clone()).collect();
+                    // Run the search against the active translation, but
+                    // finish borrowing it before writing back into self.
+                    let outcome = self.active_bible().map(|bible| {
+                        if let Some(refs) = parse_reference(&self.search_query) {
+                            // The search box holds a reference like "John
+                            // 3:16" - jump straight to it instead of
+                            // searching for it.
+                            let verses = bible.resolve(&refs);
+                            let navigate_to = verses.first().map(|v| (v.book.clone(), v.chapter));
+                            (navigate_to, verses.into_iter().cloned().collect::<Vec<_>>())
+                        } else {
+                            let results = bible.search(&self.search_query);
+                            (None, results.into_iter().cloned().collect::<Vec<_>>())
+                        }
+                    });
+
+                    if let Some((navigate_to, results)) = outcome {
+                        if navigate_to.is_some() {
+                            self.navigate_to = navigate_to;
+                        }
+                        self.search_results = results;
+                    }
                 }
             });
             
@@ -381,12 +1475,50 @@ impl eframe::App for BibleApp {
                         }
                     });
             }
-            
+
+            // Bookmarks panel
+            ui.separator();
+            ui.add_space(10.0);
+            ui.heading("Bookmarks");
+
+            ui.horizontal(|ui| {
+                if ui
+                    .add_enabled(!self.navigation_history.is_empty(), egui::Button::new("« Back"))
+                    .clicked()
+                {
+                    self.jump_back();
+                }
+
+                ui.add(
+                    TextEdit::singleline(&mut self.bookmark_input)
+                        .hint_text("key")
+                        .desired_width(30.0),
+                );
+
+                if ui.button("Mark").clicked() {
+                    if let Some(key) = self.bookmark_input.chars().next() {
+                        self.set_bookmark(key);
+                    }
+                }
+            });
+
+            ui.horizontal_wrapped(|ui| {
+                let mut sorted_bookmarks: Vec<(char, i64)> =
+                    self.bookmarks.bookmarks.iter().map(|(&k, &v)| (k, v)).collect();
+                sorted_bookmarks.sort_by_key(|(key, _)| *key);
+
+                for (key, id) in sorted_bookmarks {
+                    if let Some((book, chapter, _)) = passage_from_int(id) {
+                        if ui.button(format!("[{}] {} {}", key, book, chapter)).clicked() {
+                            self.navigate_to = Some((book, chapter));
+                        }
+                    }
+                }
+            });
+
             // After all UI elements are drawn, check if we need to navigate
             if let Some((book, chapter)) = self.navigate_to.take() {
-                self.selected_book = book;
-                self.selected_chapter = chapter;
-                self.update_chapter_display();
+                self.navigate(book, chapter);
             }
         });
     }
@@ -394,43 +1526,154 @@ impl eframe::App for BibleApp {
 
 use eframe::{egui, NativeOptions};
 
-fn main() -> eframe::Result<()> {
-    // Define paths to testament directories
-    let old_testament_path = Path::new("old_testament");
-    let new_testament_path = Path::new("new_testament");
+/// Short ids and on-disk layout for every translation this build ships, as
+/// `translations/<lowercase id>/{old_testament,new_testament}` directory pairs.
+const TRANSLATION_SOURCES: [(&str, &str); 2] = [
+    ("KJV", "translations/kjv"),
+    ("WEB", "translations/web"),
+];
 
-    
-    // Load the Bible data
-    let bible = match Bible::from_directories(old_testament_path, new_testament_path) {
-        Ok(bible) => bible,
+/// Parses a simple `--flag value` pair out of argv. Good enough for the
+/// handful of entry points this binary exposes; not meant to replace a real
+/// argument parser if the CLI surface grows much further.
+fn flag_value(args: &[String], flag: &str) -> Option<String> {
+    args.iter().position(|a| a == flag).and_then(|i| args.get(i + 1)).cloned()
+}
+
+/// `--db <path> --lookup <reference>`: answers one reference against the
+/// SQLite database at `db_path` with `sqlite_get_verse`/`sqlite_get_chapter`
+/// (one indexed query per verse/chapter), then exits without loading a
+/// `Bible` into memory at all.
+fn lookup_and_print(db_path: &str, reference: &str) -> eframe::Result<()> {
+    let Some(refs) = parse_reference(reference) else {
+        eprintln!("Could not parse reference: {}", reference);
+        return Ok(());
+    };
+    let conn = match Connection::open(db_path) {
+        Ok(conn) => conn,
         Err(e) => {
-            eprintln!("Error loading Bible: {}", e);
+            eprintln!("Error opening {}: {}", db_path, e);
             return Ok(());
         }
     };
-    
+
+    // The verse of the tuple just before a `LAST_VERSE_IN_CHAPTER` sentinel,
+    // already printed; the sentinel covers the verses after it.
+    let mut previous_verse = 0;
+    for (book, chapter, verse) in &refs {
+        if *verse == LAST_VERSE_IN_CHAPTER {
+            match sqlite_get_chapter(&conn, book, *chapter) {
+                Ok(verses) => {
+                    for v in verses.into_iter().filter(|v| v.verse_number > previous_verse) {
+                        println!("{} {}:{} {}", v.book, v.chapter, v.verse_number, v.text);
+                    }
+                }
+                Err(e) => eprintln!("Query error: {}", e),
+            }
+            continue;
+        }
+
+        previous_verse = *verse;
+        match sqlite_get_verse(&conn, book, *chapter, *verse) {
+            Ok(Some(v)) => println!("{} {}:{} {}", v.book, v.chapter, v.verse_number, v.text),
+            Ok(None) => eprintln!("Not found: {} {}:{}", book, chapter, verse),
+            Err(e) => eprintln!("Query error: {}", e),
+        }
+    }
+    Ok(())
+}
+
+/// Shared GUI startup once `translations` is loaded, regardless of which
+/// backend (flat files or SQLite) they came from: prints the loaded book
+/// order, opens the window, and runs the eframe app loop.
+fn run_gui(translations: Vec<Translation>) -> eframe::Result<()> {
+    if translations.is_empty() {
+        eprintln!("No translations loaded.");
+        return Ok(());
+    }
+
     // Debug: Print books in the order they'll appear in the app
     println!("Books in biblical order:");
-    for (i, book) in bible.books.iter().enumerate() {
+    for (i, book) in translations[0].bible.books.iter().enumerate() {
         println!("{:2}. {}", i + 1, book.name);
     }
-    
+
     // Set up window options
     let options = NativeOptions {
         viewport: egui::ViewportBuilder::default()
             .with_inner_size([800.0, 600.0]),
         ..Default::default()
     };
-    
+
     // Create the Bible App with initial chapter display
-    let mut app = BibleApp::new(bible);
-    app.update_chapter_display(); // Initialize with first chapter content
-    
+    let app = BibleApp::new(translations);
+
     // Run the app
     eframe::run_native(
         "Bible App",
         options,
         Box::new(|_cc| Ok(Box::new(app)))
-    )?;
-    Ok(())
+    )
+}
+
+fn main() -> eframe::Result<()> {
+    let args: Vec<String> = std::env::args().collect();
+
+    if let Some(db_path) = flag_value(&args, "--import-db") {
+        return match Bible::import_directories_to_sqlite(
+            Path::new("old_testament"),
+            Path::new("new_testament"),
+            Path::new(&db_path),
+        ) {
+            Ok(()) => {
+                println!("Imported old_testament/new_testament into {}", db_path);
+                Ok(())
+            }
+            Err(e) => {
+                eprintln!("Error importing to {}: {}", db_path, e);
+                Ok(())
+            }
+        };
+    }
+
+    if let Some(db_path) = flag_value(&args, "--db") {
+        if let Some(reference) = flag_value(&args, "--lookup") {
+            return lookup_and_print(&db_path, &reference);
+        }
+
+        // No --lookup: launch the normal GUI, backed by the SQLite database
+        // instead of the flat-file translations.
+        return match Bible::from_sqlite(Path::new(&db_path)) {
+            Ok(bible) => run_gui(vec![Translation { id: "DB".to_string(), bible }]),
+            Err(e) => {
+                eprintln!("Error loading database {}: {}", db_path, e);
+                Ok(())
+            }
+        };
+    }
+
+    let mut translations = Vec::new();
+    for (id, dir) in TRANSLATION_SOURCES {
+        let old_testament_path = Path::new(dir).join("old_testament");
+        let new_testament_path = Path::new(dir).join("new_testament");
+        match Bible::from_directories(&old_testament_path, &new_testament_path) {
+            Ok(bible) => translations.push(Translation { id: id.to_string(), bible }),
+            Err(e) => eprintln!("Error loading translation {}: {}", id, e),
+        }
+    }
+
+    if translations.is_empty() {
+        // Fall back to the legacy single-translation directory layout.
+        let old_testament_path = Path::new("old_testament");
+        let new_testament_path = Path::new("new_testament");
+        match Bible::from_directories(old_testament_path, new_testament_path) {
+            Ok(bible) => translations.push(Translation { id: "default".to_string(), bible }),
+            Err(e) => {
+                eprintln!("Error loading Bible: {}", e);
+                return Ok(());
+            }
+        }
+    }
+
+    run_gui(translations)
 }